@@ -1,5 +1,6 @@
 //! multipart/form-data
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::fmt;
 
 use http::HeaderMap;
@@ -11,6 +12,7 @@ use super::Body;
 /// An async multipart/form-data request.
 pub struct Form {
     inner: FormParts<Part>,
+    progress: Option<RefCell<Box<dyn FnMut(u64, Option<u64>)>>>,
 }
 
 impl Form {
@@ -52,6 +54,7 @@ impl Form {
     pub fn new() -> Form {
         Form {
             inner: FormParts::new(),
+            progress: None,
         }
     }
 
@@ -80,25 +83,225 @@ impl Form {
         self.with_inner(move |inner| inner.part(name, part))
     }
 
+    /// Registers a callback fired after each part is added to the form by
+    /// [`to_multipart_body`](Form::to_multipart_body) or when the form is
+    /// converted to `FormData`, reporting the cumulative body size of the
+    /// parts handled so far and the total body size of all parts (always
+    /// `Some`). Sizes count part bodies only, not multipart headers or
+    /// boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let form = reqwest::multipart::Form::new()
+    ///     .text("key", "value")
+    ///     .report_progress(|sent, total| {
+    ///         println!("sent {sent} of {total:?} bytes");
+    ///     });
+    /// ```
+    pub fn report_progress<F>(mut self, callback: F) -> Form
+    where
+        F: FnMut(u64, Option<u64>) + 'static,
+    {
+        self.progress = Some(RefCell::new(Box::new(callback)));
+        self
+    }
+
     fn with_inner<F>(self, func: F) -> Self
     where
         F: FnOnce(FormParts<Part>) -> FormParts<Part>,
     {
         Form {
             inner: func(self.inner),
+            progress: self.progress,
+        }
+    }
+
+    fn notify_progress(&self, sent: u64, total: Option<u64>) {
+        if let Some(progress) = &self.progress {
+            (progress.borrow_mut())(sent, total);
         }
     }
 
+    fn total_size(&self) -> Option<u64> {
+        self.progress
+            .is_some()
+            .then(|| self.inner.fields.iter().map(|(_, part)| part.size()).sum())
+    }
+
     pub(crate) fn to_form_data(&self) -> crate::Result<FormData> {
         let form = FormData::new()
             .map_err(crate::error::wasm)
             .map_err(crate::error::builder)?;
 
+        let total = self.total_size();
+        let mut sent = 0u64;
+
         for (name, part) in self.inner.fields.iter() {
             part.append_to_form(name, &form)
                 .map_err(crate::error::wasm)
                 .map_err(crate::error::builder)?;
+
+            sent += part.size();
+            self.notify_progress(sent, total);
+        }
+        Ok(form)
+    }
+
+    /// Serializes this form into a raw `multipart/form-data` byte body and
+    /// its matching `Content-Type` header value, without going through
+    /// `web_sys::FormData`.
+    ///
+    /// This is useful when the exact bytes of the body need to be known up
+    /// front, e.g. to sign or hash them before handing them to `fetch`, as
+    /// when building a signed S3 POST upload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn run() -> reqwest::Result<()> {
+    /// let form = reqwest::multipart::Form::new().text("key", "value");
+    /// let (body, content_type) = form.to_multipart_body().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn to_multipart_body(&self) -> crate::Result<(Vec<u8>, String)> {
+        let boundary = format!("{:016x}{:016x}", random_u64(), random_u64());
+        let mut body = Vec::new();
+
+        let total = self.total_size();
+        let mut sent = 0u64;
+
+        for (name, part) in self.inner.fields.iter() {
+            part.write_field(&boundary, name, &mut body).await?;
+
+            sent += part.size();
+            self.notify_progress(sent, total);
+        }
+        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+        Ok((body, format!("multipart/form-data; boundary={boundary}")))
+    }
+
+    /// Builds a `Form` from a `web_sys::FormData`, the inverse of
+    /// [`to_form_data`](Form::to_form_data).
+    ///
+    /// Each string field becomes a text [`Part`]; each file field is read
+    /// into bytes via its `arrayBuffer()` promise and becomes a [`Part`]
+    /// carrying the original filename and content type.
+    ///
+    /// A response body received as a `web_sys::FormData` (e.g. from
+    /// `web_sys::Request::form_data`) can be decoded back into a `Form`
+    /// this way, giving a symmetric round trip with [`to_form_data`].
+    ///
+    /// [`to_form_data`]: Form::to_form_data
+    pub async fn from_form_data(form_data: &web_sys::FormData) -> crate::Result<Form> {
+        use wasm_bindgen::JsCast;
+
+        let mut form = Form::new();
+
+        for entry in form_data.entries() {
+            let entry = entry
+                .map_err(crate::error::wasm)
+                .map_err(crate::error::builder)?;
+            let pair: js_sys::Array = entry.unchecked_into();
+
+            let name = pair
+                .get(0)
+                .as_string()
+                .ok_or_else(|| crate::error::builder("form field name is not a string"))?;
+            let value = pair.get(1);
+
+            let part = if let Some(text) = value.as_string() {
+                Part::text(text)
+            } else {
+                let file = web_sys::File::from(value);
+
+                let array_buffer =
+                    crate::wasm::promise::<wasm_bindgen::JsValue>(file.array_buffer())
+                        .await
+                        .map_err(crate::error::wasm)
+                        .map_err(crate::error::builder)?;
+                let bytes = js_sys::Uint8Array::new(&array_buffer).to_vec();
+
+                let mut part = Part::bytes(bytes).file_name(file.name());
+                let mime = file.type_();
+                if !mime.is_empty() {
+                    part = part.mime_str(&mime)?;
+                }
+                part
+            };
+
+            form = form.part(name, part);
+        }
+
+        Ok(form)
+    }
+
+    /// Parses a raw `multipart/form-data` byte body (as produced by
+    /// [`to_multipart_body`](Form::to_multipart_body), or received in a
+    /// response whose `Content-Type` carries a `boundary`) back into a
+    /// `Form`, recovering each field's name, filename, content type, and
+    /// bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let (body, content_type) = (Vec::new(), String::from("multipart/form-data; boundary=X"));
+    /// let boundary = content_type.split("boundary=").nth(1).unwrap();
+    /// let form = reqwest::multipart::Form::from_multipart_bytes(&body, boundary);
+    /// ```
+    pub fn from_multipart_bytes(body: &[u8], boundary: &str) -> crate::Result<Form> {
+        let mut form = Form::new();
+        let delimiter = format!("--{boundary}").into_bytes();
+
+        if find_subslice(body, &delimiter).is_none() {
+            return Err(crate::error::builder(
+                "multipart body does not contain the given boundary",
+            ));
+        }
+
+        for segment in split_on(body, &delimiter) {
+            let segment = trim_crlf(segment);
+            if segment.is_empty() || segment == b"--" {
+                continue;
+            }
+
+            let header_end = find_subslice(segment, b"\r\n\r\n")
+                .ok_or_else(|| crate::error::builder("multipart field is missing a header terminator"))?;
+            let (headers, rest) = segment.split_at(header_end);
+            let field_body = &rest[4..];
+
+            let mut name = None;
+            let mut file_name = None;
+            let mut mime = None;
+
+            for line in headers.split(|&b| b == b'\n') {
+                let line = std::str::from_utf8(trim_crlf(line))
+                    .map_err(crate::error::builder)?
+                    .trim();
+                if let Some(value) = strip_prefix_ci(line, "content-disposition:") {
+                    name = content_disposition_param(value, "name");
+                    file_name = content_disposition_param(value, "filename");
+                } else if let Some(value) = strip_prefix_ci(line, "content-type:") {
+                    mime = value.trim().parse().ok();
+                }
+            }
+
+            let name = name
+                .ok_or_else(|| crate::error::builder("multipart field is missing a name"))?;
+
+            let mut part = Part::bytes(field_body.to_vec());
+            if let Some(file_name) = file_name {
+                part = part.file_name(file_name);
+            }
+            if let Some(mime) = mime {
+                part = part.mime(mime);
+            }
+
+            form = form.part(name, part);
         }
+
         Ok(form)
     }
 }
@@ -141,6 +344,13 @@ impl Part {
         Part::new(value.into())
     }
 
+    /// Makes a new parameter from a serializable value, encoding it as JSON
+    /// and setting the part's mime to `application/json`.
+    pub fn json<T: serde::Serialize + ?Sized>(value: &T) -> crate::Result<Part> {
+        let body = serde_json::to_vec(value).map_err(crate::error::builder)?;
+        Ok(Part::bytes(body).mime(mime_guess::mime::APPLICATION_JSON))
+    }
+
     fn new(value: Body) -> Part {
         Part {
             meta: PartMetadata::new(),
@@ -191,26 +401,43 @@ impl Part {
             .as_single()
             .expect("A part's body can't be multipart itself");
 
-        let mut mime_type = self.metadata().mime.as_ref();
+        let guessed_mime = self.metadata().mime.clone().or_else(|| {
+            self.metadata()
+                .file_name
+                .as_ref()
+                .and_then(|file_name| mime_guess::from_path(file_name.as_ref()).first())
+        });
+        let mime_type = guessed_mime.as_ref();
 
         if let super::body::Single::Blob(blob) = single {
-            if let Some(file_name) = &self.metadata().file_name {
-                return form.append_with_blob_and_filename(name, blob, file_name);
+            // `Blob::type_` is set at construction time and can't be mutated,
+            // so a guessed-from-filename mime can only be applied by cloning
+            // the blob's bytes into a retyped one via `slice`.
+            let blob = if blob.type_().is_empty() {
+                match mime_type {
+                    Some(mime) => blob
+                        .slice_with_f64_and_f64_and_content_type(0.0, blob.size(), mime.as_ref())?,
+                    None => blob.clone(),
+                }
             } else {
-                return form.append_with_blob(name, blob);
-            }
+                blob.clone()
+            };
+
+            return if let Some(file_name) = &self.metadata().file_name {
+                form.append_with_blob_and_filename(name, &blob, file_name)
+            } else {
+                form.append_with_blob(name, &blob)
+            };
         }
 
         // The JS fetch API doesn't support file names and mime types for strings. So we do our best
         // effort to use `append_with_str` and fallback to `append_with_blob_*` if that's not
         // possible.
         if let super::body::Single::Text(text) = single {
-            if mime_type.is_none() || mime_type == Some(&mime_guess::mime::TEXT_PLAIN) {
-                if self.metadata().file_name.is_none() {
-                    return form.append_with_str(name, text);
-                }
-            } else {
-                mime_type = Some(&mime_guess::mime::TEXT_PLAIN);
+            if (mime_type.is_none() || mime_type == Some(&mime_guess::mime::TEXT_PLAIN))
+                && self.metadata().file_name.is_none()
+            {
+                return form.append_with_str(name, text);
             }
         }
 
@@ -223,6 +450,71 @@ impl Part {
         }
     }
 
+    async fn write_field(
+        &self,
+        boundary: &str,
+        name: &str,
+        out: &mut Vec<u8>,
+    ) -> crate::Result<()> {
+        out.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+
+        let name = escape_disposition_param(name)?;
+        out.extend_from_slice(format!("Content-Disposition: form-data; name=\"{name}\"").as_bytes());
+        if let Some(file_name) = &self.metadata().file_name {
+            let file_name = escape_disposition_param(file_name)?;
+            out.extend_from_slice(format!("; filename=\"{file_name}\"").as_bytes());
+        }
+        out.extend_from_slice(b"\r\n");
+
+        if let Some(mime) = &self.metadata().mime {
+            out.extend_from_slice(format!("Content-Type: {mime}\r\n").as_bytes());
+        }
+
+        for (key, value) in self.metadata().headers.iter() {
+            out.extend_from_slice(key.as_str().as_bytes());
+            out.extend_from_slice(b": ");
+            out.extend_from_slice(value.as_bytes());
+            out.extend_from_slice(b"\r\n");
+        }
+
+        out.extend_from_slice(b"\r\n");
+        out.extend_from_slice(&self.read_bytes().await?);
+        out.extend_from_slice(b"\r\n");
+
+        Ok(())
+    }
+
+    /// The size in bytes of this part's body, used to report upload progress.
+    fn size(&self) -> u64 {
+        let single = self
+            .value
+            .as_single()
+            .expect("A part's body can't be multipart itself");
+
+        match single {
+            super::body::Single::Text(text) => text.len() as u64,
+            super::body::Single::Blob(blob) => blob.size() as u64,
+        }
+    }
+
+    async fn read_bytes(&self) -> crate::Result<Vec<u8>> {
+        let single = self
+            .value
+            .as_single()
+            .expect("A part's body can't be multipart itself");
+
+        match single {
+            super::body::Single::Text(text) => Ok(text.as_bytes().to_vec()),
+            super::body::Single::Blob(blob) => {
+                let array_buffer = crate::wasm::promise::<wasm_bindgen::JsValue>(blob.array_buffer())
+                    .await
+                    .map_err(crate::error::wasm)
+                    .map_err(crate::error::builder)?;
+                Ok(js_sys::Uint8Array::new(&array_buffer).to_vec())
+            }
+        }
+    }
+
     fn blob(&self, mime_type: Option<&Mime>) -> crate::Result<web_sys::Blob> {
         use web_sys::Blob;
         use web_sys::BlobPropertyBag;
@@ -331,6 +623,112 @@ impl PartMetadata {
     }
 }
 
+// ===== multipart byte-body encoding/parsing helpers =====
+
+/// A random `u64` built from `Math.random()`, good enough for a multipart
+/// boundary token. Avoids pulling in a dedicated RNG crate for something
+/// `js_sys` (already a dependency here) can provide directly.
+fn random_u64() -> u64 {
+    let hi = (js_sys::Math::random() * u32::MAX as f64) as u64;
+    let lo = (js_sys::Math::random() * u32::MAX as f64) as u64;
+    (hi << 32) | lo
+}
+
+/// Escapes `"` and `\` for use inside a `Content-Disposition` quoted-string
+/// param, the inverse of the unescaping in [`content_disposition_param`].
+/// Rejects CR/LF, which would otherwise let a field name or filename inject
+/// extra header lines into the part.
+fn escape_disposition_param(value: &str) -> crate::Result<String> {
+    if value.contains(['\r', '\n']) {
+        return Err(crate::error::builder(
+            "multipart field name or filename cannot contain CR or LF",
+        ));
+    }
+    Ok(value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn split_on<'a>(haystack: &'a [u8], needle: &[u8]) -> Vec<&'a [u8]> {
+    let mut segments = Vec::new();
+    let mut rest = haystack;
+    while let Some(pos) = find_subslice(rest, needle) {
+        segments.push(&rest[..pos]);
+        rest = &rest[pos + needle.len()..];
+    }
+    segments.push(rest);
+    if segments.len() <= 1 {
+        // `needle` never matched, so there's no preamble/postamble pair to drop.
+        return Vec::new();
+    }
+    // The first segment is the (empty) preamble before the first boundary,
+    // and the last is whatever trails the closing `--boundary--`.
+    segments.drain(1..segments.len() - 1).collect()
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Strips a single leading and a single trailing CRLF, matching exactly the
+/// separators [`Form::to_multipart_body`](Form::to_multipart_body) writes
+/// around each field.
+fn trim_crlf(bytes: &[u8]) -> &[u8] {
+    let bytes = bytes.strip_prefix(b"\r\n").unwrap_or(bytes);
+    bytes.strip_suffix(b"\r\n").unwrap_or(bytes)
+}
+
+fn strip_prefix_ci<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    if line.len() >= prefix.len() && line[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&line[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Splits a `Content-Disposition` value into its `;`-separated params,
+/// honoring quoted-strings so a `;` or escaped `"` inside a quoted value
+/// doesn't get mistaken for a param separator.
+fn split_disposition_params(value: &str) -> Vec<&str> {
+    let bytes = value.as_bytes();
+    let mut params = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_quotes => i += 1, // skip the escaped byte entirely
+            b'"' => in_quotes = !in_quotes,
+            b';' if !in_quotes => {
+                params.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    params.push(&value[start..]);
+    params
+}
+
+fn content_disposition_param(value: &str, key: &str) -> Option<String> {
+    for param in split_disposition_params(value).into_iter().skip(1) {
+        let Some((k, v)) = param.trim().split_once('=') else {
+            continue;
+        };
+        if k.trim() != key {
+            continue;
+        }
+        let v = v.trim();
+        let v = v
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(v);
+        return Some(v.replace("\\\"", "\"").replace("\\\\", "\\"));
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -446,4 +844,174 @@ mod tests {
         assert_eq!(blob_file.type_(), blob_type);
         assert_eq!(blob_file.size() as u64, blob_data.len() as u64);
     }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_guesses_mime_from_file_name() {
+        use super::{Form, Part};
+        use web_sys::{File, FormData};
+
+        let name = "upload";
+        let part = Part::bytes(vec![0u8, 1, 2]).file_name("logo.png");
+        let form = Form::new().part(name, part);
+
+        let init = web_sys::RequestInit::new();
+        init.set_method(http::Method::POST.as_str());
+        init.set_body(
+            form.to_form_data()
+                .expect("could not convert to FormData")
+                .as_ref(),
+        );
+
+        let js_req = web_sys::Request::new_with_str_and_init("", &init)
+            .expect("could not create JS request");
+        let form_data_promise = js_req.form_data().expect("could not get form_data promise");
+        let form_data = crate::wasm::promise::<FormData>(form_data_promise)
+            .await
+            .expect("could not get body as form data");
+
+        let file = File::from(form_data.get(name));
+        assert_eq!(file.type_(), "image/png");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_report_progress() {
+        use super::Form;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let calls_clone = calls.clone();
+
+        let form = Form::new()
+            .text("a", "12345")
+            .text("b", "1234567890")
+            .report_progress(move |sent, total| {
+                calls_clone.borrow_mut().push((sent, total));
+            });
+
+        form.to_form_data().expect("could not convert to FormData");
+
+        assert_eq!(*calls.borrow(), vec![(5, Some(15)), (15, Some(15))]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_multipart_from_bytes_missing_boundary_errors() {
+        use super::Form;
+
+        assert!(Form::from_multipart_bytes(b"not a multipart body", "boundary123").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_body_round_trip() {
+        use super::{Form, Part};
+
+        let form = Form::new()
+            .text("title", "hello world")
+            .part("file", Part::bytes(vec![1u8, 2, 3]).file_name("data.bin"));
+
+        let (body, content_type) = form
+            .to_multipart_body()
+            .await
+            .expect("failed to serialize multipart body");
+
+        let boundary = content_type
+            .split("boundary=")
+            .nth(1)
+            .expect("missing boundary in content-type");
+
+        let decoded = Form::from_multipart_bytes(&body, boundary)
+            .expect("failed to parse multipart body");
+
+        assert_eq!(decoded.inner.fields.len(), 2);
+        assert_eq!(decoded.inner.fields[0].0, "title");
+        assert_eq!(decoded.inner.fields[1].0, "file");
+        assert_eq!(
+            decoded.inner.fields[1].1.meta.file_name.as_deref(),
+            Some("data.bin")
+        );
+        assert_eq!(
+            decoded.inner.fields[1].1.read_bytes().await.unwrap(),
+            vec![1u8, 2, 3]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_body_round_trips_special_characters() {
+        use super::Form;
+
+        let form = Form::new().text("say \"hi\"", "value");
+
+        let (body, content_type) = form
+            .to_multipart_body()
+            .await
+            .expect("failed to serialize multipart body");
+        let boundary = content_type.split("boundary=").nth(1).unwrap();
+
+        let decoded =
+            Form::from_multipart_bytes(&body, boundary).expect("failed to parse multipart body");
+
+        assert_eq!(decoded.inner.fields[0].0, "say \"hi\"");
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_body_round_trips_semicolon_in_file_name() {
+        use super::{Form, Part};
+
+        let form = Form::new().part("file", Part::bytes(vec![1u8]).file_name("a;b.txt"));
+
+        let (body, content_type) = form
+            .to_multipart_body()
+            .await
+            .expect("failed to serialize multipart body");
+        let boundary = content_type.split("boundary=").nth(1).unwrap();
+
+        let decoded =
+            Form::from_multipart_bytes(&body, boundary).expect("failed to parse multipart body");
+
+        assert_eq!(
+            decoded.inner.fields[0].1.meta.file_name.as_deref(),
+            Some("a;b.txt")
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_body_rejects_crlf_in_name() {
+        use super::Form;
+
+        let form = Form::new().text("bad\r\nname", "value");
+
+        assert!(form.to_multipart_body().await.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_multipart_from_form_data_round_trip() {
+        use super::Form;
+        use web_sys::FormData;
+
+        let form_data = FormData::new().expect("failed to create FormData");
+        form_data
+            .append_with_str("title", "hello")
+            .expect("failed to append str");
+
+        let decoded = Form::from_form_data(&form_data)
+            .await
+            .expect("failed to parse FormData");
+
+        assert_eq!(decoded.inner.fields.len(), 1);
+        assert_eq!(decoded.inner.fields[0].0, "title");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_multipart_json_part() {
+        use super::Part;
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct Meta {
+            id: u32,
+        }
+
+        let part = Part::json(&Meta { id: 42 }).expect("failed to build json part");
+        assert_eq!(part.meta.mime.as_ref().map(|m| m.as_ref()), Some("application/json"));
+    }
 }